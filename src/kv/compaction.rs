@@ -0,0 +1,47 @@
+use crate::proto::etcdserverpb;
+
+pub struct CompactionRequest {
+    proto: etcdserverpb::CompactionRequest,
+}
+
+impl CompactionRequest {
+    pub fn new(revision: i64) -> Self {
+        Self {
+            proto: etcdserverpb::CompactionRequest {
+                revision,
+                physical: false,
+            },
+        }
+    }
+
+    /// Whether the compaction should wait until all compacted entries are
+    /// physically removed before returning a response
+    pub fn physical(mut self, physical: bool) -> Self {
+        self.proto.physical = physical;
+        self
+    }
+}
+
+impl Into<etcdserverpb::CompactionRequest> for CompactionRequest {
+    fn into(self) -> etcdserverpb::CompactionRequest {
+        self.proto
+    }
+}
+
+#[derive(Debug)]
+pub struct CompactionResponse {
+    proto: etcdserverpb::CompactionResponse,
+}
+
+impl CompactionResponse {
+    /// Get the header of the response
+    pub fn header(&self) -> Option<&etcdserverpb::ResponseHeader> {
+        self.proto.header.as_ref()
+    }
+}
+
+impl From<etcdserverpb::CompactionResponse> for CompactionResponse {
+    fn from(resp: etcdserverpb::CompactionResponse) -> Self {
+        Self { proto: resp }
+    }
+}
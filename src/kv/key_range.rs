@@ -0,0 +1,32 @@
+/// A key or a range of keys.
+#[derive(Debug, Clone)]
+pub struct KeyRange {
+    pub(crate) key: Vec<u8>,
+    pub(crate) range_end: Vec<u8>,
+}
+
+impl KeyRange {
+    /// Match a single key
+    pub fn key(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            range_end: vec![],
+        }
+    }
+
+    /// Match all keys in `[key, range_end)`
+    pub fn range(key: impl Into<Vec<u8>>, range_end: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            range_end: range_end.into(),
+        }
+    }
+}
+
+/// Lets a single `KeyRange` drive several builder calls (e.g. multiple txn
+/// compares) without the caller having to write `.clone()` at each call site.
+impl From<&KeyRange> for KeyRange {
+    fn from(key_range: &KeyRange) -> Self {
+        key_range.clone()
+    }
+}
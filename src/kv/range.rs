@@ -0,0 +1,88 @@
+use super::KeyRange;
+use crate::proto::etcdserverpb;
+use etcdserverpb::range_request::{SortOrder as ProtoSortOrder, SortTarget as ProtoSortTarget};
+
+pub struct RangeRequest {
+    proto: etcdserverpb::RangeRequest,
+}
+
+impl RangeRequest {
+    pub fn new(key_range: impl Into<KeyRange>) -> Self {
+        let key_range = key_range.into();
+
+        Self {
+            proto: etcdserverpb::RangeRequest {
+                key: key_range.key,
+                range_end: key_range.range_end,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Sort returned keys by the given target and order
+    pub fn sort_by(mut self, target: SortTarget, order: SortOrder) -> Self {
+        self.proto.sort_target = ProtoSortTarget::from(target) as i32;
+        self.proto.sort_order = ProtoSortOrder::from(order) as i32;
+        self
+    }
+
+    /// Limit the number of keys returned
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.proto.limit = limit as i64;
+        self
+    }
+
+    /// Return only the count of keys matching the range, not the keys themselves
+    pub fn count_only(mut self) -> Self {
+        self.proto.count_only = true;
+        self
+    }
+
+    /// Return only the keys matching the range, not their values
+    pub fn keys_only(mut self) -> Self {
+        self.proto.keys_only = true;
+        self
+    }
+}
+
+impl Into<etcdserverpb::RangeRequest> for RangeRequest {
+    fn into(self) -> etcdserverpb::RangeRequest {
+        self.proto
+    }
+}
+
+/// Target to sort returned range results by
+pub enum SortTarget {
+    Key,
+    Version,
+    Create,
+    Mod,
+    Value,
+}
+
+impl From<SortTarget> for ProtoSortTarget {
+    fn from(target: SortTarget) -> Self {
+        match target {
+            SortTarget::Key => ProtoSortTarget::Key,
+            SortTarget::Version => ProtoSortTarget::Version,
+            SortTarget::Create => ProtoSortTarget::Create,
+            SortTarget::Mod => ProtoSortTarget::Mod,
+            SortTarget::Value => ProtoSortTarget::Value,
+        }
+    }
+}
+
+/// Order to sort returned range results in
+pub enum SortOrder {
+    Ascend,
+    Descend,
+}
+
+impl From<SortOrder> for ProtoSortOrder {
+    fn from(order: SortOrder) -> Self {
+        match order {
+            SortOrder::Ascend => ProtoSortOrder::Ascend,
+            SortOrder::Descend => ProtoSortOrder::Descend,
+        }
+    }
+}
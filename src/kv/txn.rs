@@ -1,4 +1,4 @@
-use super::{DeleteRequest, KeyRange, PutRequest, RangeRequest};
+use super::{DeleteRequest, DeleteResponse, KeyRange, PutRequest, PutResponse, RangeRequest, RangeResponse};
 use crate::proto::etcdserverpb;
 use etcdserverpb::compare::{CompareResult, CompareTarget, TargetUnion};
 use etcdserverpb::Compare;
@@ -19,7 +19,13 @@ impl TxnRequest {
     }
 
     /// Add a version compare
-    pub fn when_version(mut self, key_range: KeyRange, cmp: TxnCmp, version: usize) -> Self {
+    pub fn when_version(
+        mut self,
+        key_range: impl Into<KeyRange>,
+        cmp: TxnCmp,
+        version: usize,
+    ) -> Self {
+        let key_range = key_range.into();
         let result: CompareResult = cmp.into();
         self.proto.compare.push(Compare {
             result: result as i32,
@@ -34,10 +40,11 @@ impl TxnRequest {
     /// Add a create revision compare
     pub fn when_create_revision(
         mut self,
-        key_range: KeyRange,
+        key_range: impl Into<KeyRange>,
         cmp: TxnCmp,
         revision: usize,
     ) -> Self {
+        let key_range = key_range.into();
         let result: CompareResult = cmp.into();
         self.proto.compare.push(Compare {
             result: result as i32,
@@ -50,7 +57,13 @@ impl TxnRequest {
     }
 
     /// Add a mod revision compare
-    pub fn when_mod_revision(mut self, key_range: KeyRange, cmp: TxnCmp, revision: usize) -> Self {
+    pub fn when_mod_revision(
+        mut self,
+        key_range: impl Into<KeyRange>,
+        cmp: TxnCmp,
+        revision: usize,
+    ) -> Self {
+        let key_range = key_range.into();
         let result: CompareResult = cmp.into();
         self.proto.compare.push(Compare {
             result: result as i32,
@@ -63,10 +76,11 @@ impl TxnRequest {
     }
 
     /// Add a value compare
-    pub fn when_value<V>(mut self, key_range: KeyRange, cmp: TxnCmp, value: V) -> Self
+    pub fn when_value<V>(mut self, key_range: impl Into<KeyRange>, cmp: TxnCmp, value: V) -> Self
     where
         V: Into<Vec<u8>>,
     {
+        let key_range = key_range.into();
         let result: CompareResult = cmp.into();
         self.proto.compare.push(Compare {
             result: result as i32,
@@ -78,6 +92,20 @@ impl TxnRequest {
         self
     }
 
+    /// Add a lease compare
+    pub fn when_lease(mut self, key_range: impl Into<KeyRange>, cmp: TxnCmp, lease: i64) -> Self {
+        let key_range = key_range.into();
+        let result: CompareResult = cmp.into();
+        self.proto.compare.push(Compare {
+            result: result as i32,
+            target: CompareTarget::Lease as i32,
+            key: key_range.key,
+            range_end: key_range.range_end,
+            target_union: Some(TargetUnion::Lease(lease)),
+        });
+        self
+    }
+
     /// If compare success, then execute
     pub fn and_then<O>(mut self, op: O) -> Self
     where
@@ -174,8 +202,51 @@ pub struct TxnResponse {
     proto: etcdserverpb::TxnResponse,
 }
 
+impl TxnResponse {
+    /// Returns true if the compare evaluated to true, meaning the `success`
+    /// operations were executed; false means the `failure` operations ran instead.
+    pub fn succeeded(&self) -> bool {
+        self.proto.succeeded
+    }
+
+    /// Takes the per-operation responses, in the order the operations were added.
+    pub fn op_responses(self) -> Vec<TxnOpResponse> {
+        let mut op_responses = Vec::with_capacity(self.proto.responses.len());
+
+        for resp in self.proto.responses {
+            if let Some(response) = resp.response {
+                op_responses.push(response.into());
+            }
+        }
+
+        op_responses
+    }
+}
+
 impl From<etcdserverpb::TxnResponse> for TxnResponse {
     fn from(resp: etcdserverpb::TxnResponse) -> Self {
         Self { proto: resp }
     }
 }
+
+/// Transaction operation response
+#[derive(Debug)]
+pub enum TxnOpResponse {
+    Range(RangeResponse),
+    Put(PutResponse),
+    Delete(DeleteResponse),
+    Txn(TxnResponse),
+}
+
+impl From<etcdserverpb::response_op::Response> for TxnOpResponse {
+    fn from(resp: etcdserverpb::response_op::Response) -> Self {
+        use etcdserverpb::response_op::Response;
+
+        match resp {
+            Response::ResponseRange(resp) => TxnOpResponse::Range(resp.into()),
+            Response::ResponsePut(resp) => TxnOpResponse::Put(resp.into()),
+            Response::ResponseDeleteRange(resp) => TxnOpResponse::Delete(resp.into()),
+            Response::ResponseTxn(resp) => TxnOpResponse::Txn(resp.into()),
+        }
+    }
+}